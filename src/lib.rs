@@ -0,0 +1,7 @@
+// rsmath/src/lib.rs
+
+mod matrix;
+mod sparse;
+
+pub use matrix::{Index2D, LUDecomposition, Matrix, Parity, Vector};
+pub use sparse::SparseMatrix;
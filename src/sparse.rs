@@ -0,0 +1,372 @@
+// rsmath/src/sparse.rs
+
+use std::collections::{BTreeSet, HashMap};
+
+use crate::Matrix;
+
+/// The relative numerical threshold below which a candidate pivot is
+/// rejected even if it minimizes the Markowitz count, to bound round-off.
+const PIVOT_THRESHOLD: f64 = 0.01;
+
+/// A matrix of floating point numbers stored as a coordinate list of nonzero
+/// entries, indexed by row and column, for problems dominated by zeros where
+/// a dense [`Matrix`]'s O(n^3) factorization is wasteful.
+#[derive(Clone, Debug)]
+pub struct SparseMatrix {
+    rows: usize,
+    cols: usize,
+    /// Nonzero entries, keyed by `(row, col)`.
+    entries: HashMap<(usize, usize), f64>,
+    /// For each row, the columns with a nonzero entry in that row.
+    row_nz: Vec<BTreeSet<usize>>,
+    /// For each column, the rows with a nonzero entry in that column.
+    col_nz: Vec<BTreeSet<usize>>,
+}
+
+impl SparseMatrix {
+    /// Creates an empty sparse matrix with the given number of rows and columns.
+    pub fn new(rows: usize, cols: usize) -> Result<Self, &'static str> {
+        if rows == 0 || cols == 0 {
+            return Err("Matrix dimensions cannot be zero");
+        }
+
+        Ok(SparseMatrix {
+            rows,
+            cols,
+            entries: HashMap::new(),
+            row_nz: vec![BTreeSet::new(); rows],
+            col_nz: vec![BTreeSet::new(); cols],
+        })
+    }
+
+    /// Returns the number of rows in the matrix.
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Returns the number of columns in the matrix.
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Returns the number of stored nonzero entries.
+    pub fn nnz(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Accumulates `val` into the element at `(row, col)`, inserting a new
+    /// nonzero entry if one is not already present there and dropping the
+    /// entry if the accumulated value becomes exactly zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsmath::SparseMatrix;
+    ///
+    /// let mut mat = SparseMatrix::new(2, 2).unwrap();
+    /// mat.add_element(0, 0, 1.0).unwrap();
+    /// mat.add_element(0, 0, 1.0).unwrap();
+    /// assert_eq!(mat.get(0, 0), Ok(2.0));
+    /// assert_eq!(mat.nnz(), 1);
+    /// ```
+    pub fn add_element(&mut self, row: usize, col: usize, val: f64) -> Result<(), &'static str> {
+        if row >= self.rows || col >= self.cols {
+            return Err("Index out of bounds");
+        }
+
+        let updated = self.entries.get(&(row, col)).copied().unwrap_or(0.0) + val;
+        self.set_entry(row, col, updated);
+        Ok(())
+    }
+
+    /// Returns the value of the specified element, or `0.0` if it has no
+    /// stored nonzero entry.
+    pub fn get(&self, row: usize, col: usize) -> Result<f64, &'static str> {
+        if row >= self.rows || col >= self.cols {
+            return Err("Index out of bounds");
+        }
+        Ok(self.entries.get(&(row, col)).copied().unwrap_or(0.0))
+    }
+
+    /// Converts a dense [`Matrix`] into a `SparseMatrix`, dropping exact zeros.
+    pub fn from_dense(mat: &Matrix<f64>) -> Result<Self, &'static str> {
+        let mut sparse = SparseMatrix::new(mat.rows(), mat.cols())?;
+        for i in 0..mat.rows() {
+            for j in 0..mat.cols() {
+                let val = mat.get((i, j))?;
+                if val != 0.0 {
+                    sparse.set_entry(i, j, val);
+                }
+            }
+        }
+        Ok(sparse)
+    }
+
+    /// Converts this sparse matrix into a dense [`Matrix`].
+    pub fn to_dense(&self) -> Result<Matrix<f64>, &'static str> {
+        let mut data = vec![0.0; self.rows * self.cols];
+        for (&(i, j), &val) in &self.entries {
+            data[i * self.cols + j] = val;
+        }
+        Matrix::from_data(self.rows, self.cols, data)
+    }
+
+    /// Solves `A x = rhs` via Gaussian elimination with Markowitz-ordered
+    /// pivot selection.
+    ///
+    /// At each step, the pivot `(i, j)` is chosen among the candidates
+    /// satisfying `|a_ij| >= threshold * max_in_col(j)` (the numerical
+    /// threshold that bounds round-off) to minimize the Markowitz product
+    /// `(nnz(row i) - 1) * (nnz(col j) - 1)`, which bounds the fill-in
+    /// introduced by eliminating through it. New nonzeros created by
+    /// elimination ("fill") are inserted into the coordinate structure and
+    /// update the row/column counts for subsequent pivot choices.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the matrix is not square, if `rhs` does not match
+    /// its dimension, or if no nonzero entry remains in the rows and columns
+    /// not yet pivoted (the matrix is structurally singular). The largest
+    /// entry of a column always passes the threshold, so the threshold alone
+    /// never causes this error.
+    pub fn solve(&self, rhs: &[f64]) -> Result<Vec<f64>, &'static str> {
+        if self.rows != self.cols {
+            return Err("Matrix is not square");
+        }
+        if rhs.len() != self.rows {
+            return Err("Matrix dimensions do not match");
+        }
+
+        let n = self.rows;
+        let mut a = self.entries.clone();
+        let mut row_nz = self.row_nz.clone();
+        let mut col_nz = self.col_nz.clone();
+        let mut b = rhs.to_vec();
+
+        let mut active_rows: BTreeSet<usize> = (0..n).collect();
+        let mut active_cols: BTreeSet<usize> = (0..n).collect();
+        let mut pivot_order: Vec<(usize, usize)> = Vec::with_capacity(n);
+
+        for _ in 0..n {
+            let max_in_col: HashMap<usize, f64> = active_cols
+                .iter()
+                .map(|&j| {
+                    let max = col_nz[j]
+                        .iter()
+                        .filter(|i| active_rows.contains(i))
+                        .map(|&i| a[&(i, j)].abs())
+                        .fold(0.0_f64, f64::max);
+                    (j, max)
+                })
+                .collect();
+
+            let mut best: Option<(usize, usize, usize)> = None; // (markowitz count, row, col)
+            for &i in &active_rows {
+                for &j in row_nz[i].iter().filter(|j| active_cols.contains(j)) {
+                    let val = a[&(i, j)];
+                    if val.abs() < PIVOT_THRESHOLD * max_in_col[&j] {
+                        continue;
+                    }
+
+                    let row_count = row_nz[i].iter().filter(|c| active_cols.contains(c)).count();
+                    let col_count = col_nz[j].iter().filter(|r| active_rows.contains(r)).count();
+                    let markowitz = (row_count - 1) * (col_count - 1);
+
+                    let improves = match best {
+                        Some((best_markowitz, _, _)) => markowitz < best_markowitz,
+                        None => true,
+                    };
+                    if improves {
+                        best = Some((markowitz, i, j));
+                    }
+                }
+            }
+
+            let (_, pivot_row, pivot_col) = best.ok_or("Matrix is singular")?;
+            let pivot_val = a[&(pivot_row, pivot_col)];
+
+            let rows_to_eliminate: Vec<usize> = col_nz[pivot_col]
+                .iter()
+                .copied()
+                .filter(|i| *i != pivot_row && active_rows.contains(i))
+                .collect();
+            let pivot_row_cols: Vec<usize> = row_nz[pivot_row].iter().copied().collect();
+
+            for k in rows_to_eliminate {
+                let factor = a[&(k, pivot_col)] / pivot_val;
+                for &c in &pivot_row_cols {
+                    let new_val =
+                        a.get(&(k, c)).copied().unwrap_or(0.0) - factor * a[&(pivot_row, c)];
+                    Self::write_entry(&mut a, &mut row_nz, &mut col_nz, k, c, new_val);
+                }
+                b[k] -= factor * b[pivot_row];
+            }
+
+            pivot_order.push((pivot_row, pivot_col));
+            active_rows.remove(&pivot_row);
+            active_cols.remove(&pivot_col);
+        }
+
+        let mut x = vec![0.0; n];
+        for &(row, col) in pivot_order.iter().rev() {
+            let mut sum = b[row];
+            for &c in &row_nz[row] {
+                if c != col {
+                    sum -= a.get(&(row, c)).copied().unwrap_or(0.0) * x[c];
+                }
+            }
+            x[col] = sum / a[&(row, col)];
+        }
+
+        Ok(x)
+    }
+
+    /// Writes `val` into `(row, col)` on `self`'s own structure, updating the
+    /// row/column nonzero sets, and dropping the entry on exact zero.
+    fn set_entry(&mut self, row: usize, col: usize, val: f64) {
+        Self::write_entry(
+            &mut self.entries,
+            &mut self.row_nz,
+            &mut self.col_nz,
+            row,
+            col,
+            val,
+        );
+    }
+
+    /// Writes `val` into `(row, col)` of the given entry/nonzero structures,
+    /// inserting a new nonzero on fill-in or removing the entry on exact zero.
+    fn write_entry(
+        entries: &mut HashMap<(usize, usize), f64>,
+        row_nz: &mut [BTreeSet<usize>],
+        col_nz: &mut [BTreeSet<usize>],
+        row: usize,
+        col: usize,
+        val: f64,
+    ) {
+        if val == 0.0 {
+            if entries.remove(&(row, col)).is_some() {
+                row_nz[row].remove(&col);
+                col_nz[col].remove(&row);
+            }
+        } else {
+            if entries.insert((row, col), val).is_none() {
+                row_nz[row].insert(col);
+                col_nz[col].insert(row);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_element_accumulates() {
+        let mut mat = SparseMatrix::new(2, 2).unwrap();
+        mat.add_element(0, 1, 3.0).unwrap();
+        mat.add_element(0, 1, -1.0).unwrap();
+        assert_eq!(mat.get(0, 1), Ok(2.0));
+        assert_eq!(mat.get(1, 0), Ok(0.0));
+        assert_eq!(mat.nnz(), 1);
+
+        mat.add_element(0, 1, -2.0).unwrap();
+        assert_eq!(mat.get(0, 1), Ok(0.0));
+        assert_eq!(mat.nnz(), 0);
+    }
+
+    #[test]
+    fn test_dense_round_trip() {
+        let dense = Matrix::from_data(2, 2, vec![1.0, 0.0, 0.0, 4.0]).unwrap();
+        let sparse = SparseMatrix::from_dense(&dense).unwrap();
+        assert_eq!(sparse.nnz(), 2);
+        assert_eq!(sparse.to_dense().unwrap(), dense);
+    }
+
+    #[test]
+    fn test_solve_diagonal() {
+        let mut mat = SparseMatrix::new(3, 3).unwrap();
+        mat.add_element(0, 0, 2.0).unwrap();
+        mat.add_element(1, 1, 4.0).unwrap();
+        mat.add_element(2, 2, 5.0).unwrap();
+
+        let x = mat.solve(&[4.0, 8.0, 10.0]).unwrap();
+        assert_eq!(x, vec![2.0, 2.0, 2.0]);
+    }
+
+    #[test]
+    fn test_solve_matches_dense() {
+        let dense =
+            Matrix::from_data(3, 3, vec![4.0, 0.0, 1.0, 0.0, 3.0, 0.0, 2.0, 0.0, 5.0]).unwrap();
+        let sparse = SparseMatrix::from_dense(&dense).unwrap();
+
+        let b = Matrix::from_data(3, 1, vec![9.0, 6.0, 12.0]).unwrap();
+        let expected = dense.solve(&b).unwrap();
+
+        let x = sparse.solve(&[9.0, 6.0, 12.0]).unwrap();
+        for (i, value) in x.iter().enumerate() {
+            assert!((value - expected.get((i, 0)).unwrap()).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_solve_rejects_non_square() {
+        let mut mat = SparseMatrix::new(2, 3).unwrap();
+        mat.add_element(0, 0, 1.0).unwrap();
+        assert_eq!(mat.solve(&[1.0, 1.0]), Err("Matrix is not square"));
+    }
+
+    #[test]
+    fn test_solve_rejects_rhs_length_mismatch() {
+        let mut mat = SparseMatrix::new(2, 2).unwrap();
+        mat.add_element(0, 0, 1.0).unwrap();
+        mat.add_element(1, 1, 1.0).unwrap();
+        assert_eq!(
+            mat.solve(&[1.0, 1.0, 1.0]),
+            Err("Matrix dimensions do not match")
+        );
+    }
+
+    #[test]
+    fn test_solve_rejects_singular() {
+        let mut mat = SparseMatrix::new(2, 2).unwrap();
+        mat.add_element(0, 0, 1.0).unwrap();
+        // Row 1 and column 1 are entirely empty, so no pivot is ever found.
+        assert_eq!(mat.solve(&[1.0, 1.0]), Err("Matrix is singular"));
+    }
+
+    #[test]
+    fn test_solve_rejects_pivot_below_threshold() {
+        // (0, 0) has the globally smallest Markowitz count (0) but is far
+        // below `PIVOT_THRESHOLD` relative to the largest entry in its
+        // column, so it must be rejected in favor of (1, 1), which also has
+        // Markowitz count 0 but passes the threshold check.
+        let mut mat = SparseMatrix::new(3, 3).unwrap();
+        mat.add_element(0, 0, 0.005).unwrap();
+        mat.add_element(1, 0, 10.0).unwrap();
+        mat.add_element(1, 1, 3.0).unwrap();
+        mat.add_element(2, 2, 2.0).unwrap();
+
+        let x = mat.solve(&[0.005, 13.0, 4.0]).unwrap();
+        assert_eq!(x, vec![1.0, 1.0, 2.0]);
+    }
+
+    #[test]
+    fn test_solve_with_fill_in() {
+        // Every row and column has nonzeros in two of its three positions,
+        // so eliminating any first pivot introduces a new
+        // nonzero ("fill") in a previously-empty position.
+        let dense =
+            Matrix::from_data(3, 3, vec![1.0, 1.0, 0.0, 1.0, 0.0, 1.0, 0.0, 1.0, 1.0]).unwrap();
+        let sparse = SparseMatrix::from_dense(&dense).unwrap();
+
+        let b = Matrix::from_data(3, 1, vec![2.0, 2.0, 2.0]).unwrap();
+        let expected = dense.solve(&b).unwrap();
+
+        let x = sparse.solve(&[2.0, 2.0, 2.0]).unwrap();
+        for (i, value) in x.iter().enumerate() {
+            assert!((value - expected.get((i, 0)).unwrap()).abs() < 1e-9);
+        }
+    }
+}